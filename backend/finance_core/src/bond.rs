@@ -0,0 +1,104 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::invalid_input;
+
+/// A fixed-rate, fixed-maturity bond priced off its yield to maturity.
+///
+/// Price is `Σ (coupon / (1+y)^t)` for `t = 1..n` plus `face / (1+y)^n`,
+/// where `y = ytm / periods_per_year` is the per-period yield,
+/// `coupon = face × coupon_rate / periods_per_year` and
+/// `n = years × periods_per_year`.
+#[pyclass]
+pub struct SimpleBond {
+    #[pyo3(get)]
+    pub face_value: f64,
+    #[pyo3(get)]
+    pub coupon_rate: f64,
+    #[pyo3(get)]
+    pub years: f64,
+    #[pyo3(get)]
+    pub periods_per_year: u32,
+}
+
+impl SimpleBond {
+    fn periods(&self) -> u32 {
+        (self.years * self.periods_per_year as f64).round() as u32
+    }
+
+    fn coupon(&self) -> f64 {
+        self.face_value * self.coupon_rate / self.periods_per_year as f64
+    }
+
+    fn price_at_yield(&self, ytm: f64) -> f64 {
+        let n = self.periods();
+        let coupon = self.coupon();
+        let periodic_rate = ytm / self.periods_per_year as f64;
+
+        let coupons_pv: f64 = (1..=n)
+            .map(|t| coupon / (1.0 + periodic_rate).powi(t as i32))
+            .sum();
+        let face_pv = self.face_value / (1.0 + periodic_rate).powi(n as i32);
+
+        coupons_pv + face_pv
+    }
+}
+
+#[pymethods]
+impl SimpleBond {
+    #[new]
+    fn new(face_value: f64, coupon_rate: f64, years: f64, periods_per_year: u32) -> PyResult<Self> {
+        if periods_per_year == 0 {
+            return Err(PyValueError::new_err(
+                "periods_per_year must be greater than zero",
+            ));
+        }
+        Ok(SimpleBond {
+            face_value,
+            coupon_rate,
+            years,
+            periods_per_year,
+        })
+    }
+
+    /// Present value of all remaining coupons plus the face value.
+    #[pyo3(signature = (ytm, silent=false))]
+    fn price_from_yield(&self, ytm: f64, silent: bool) -> PyResult<Option<f64>> {
+        if ytm <= -(self.periods_per_year as f64) {
+            return invalid_input("ytm must be greater than -periods_per_year", silent);
+        }
+        Ok(Some(self.price_at_yield(ytm)))
+    }
+
+    /// Inverts `price_from_yield` by bisection over yields in `[0, 10]`.
+    /// A price outside the range of prices at those yields gives the
+    /// nearer end of that interval.
+    #[pyo3(signature = (price, silent=false))]
+    fn yield_from_price(&self, price: f64, silent: bool) -> PyResult<Option<f64>> {
+        const MAX_ITERATIONS: u32 = 200;
+        const TOLERANCE: f64 = 1e-9;
+
+        if price <= 0.0 {
+            return invalid_input("price must be positive", silent);
+        }
+
+        let mut lo = 0.0_f64;
+        let mut hi = 10.0_f64;
+
+        for _ in 0..MAX_ITERATIONS {
+            let mid = (lo + hi) / 2.0;
+            let price_at_mid = self.price_at_yield(mid);
+            if (price_at_mid - price).abs() < TOLERANCE {
+                return Ok(Some(mid));
+            }
+            // price_at_yield is strictly decreasing in yield.
+            if price_at_mid > price {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        Ok(Some((lo + hi) / 2.0))
+    }
+}
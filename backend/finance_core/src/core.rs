@@ -0,0 +1,68 @@
+use pyo3::prelude::*;
+
+use crate::invalid_input;
+use crate::irr::npv;
+
+/// Net present value: `Σ cf_i / (1+rate)^i` for `i = 0..n`.
+#[pyfunction]
+#[pyo3(signature = (rate, cash_flows, silent=false))]
+pub fn calculate_npv(rate: f64, cash_flows: Vec<f64>, silent: bool) -> PyResult<Option<f64>> {
+    if cash_flows.is_empty() {
+        return invalid_input("cash_flows must not be empty", silent);
+    }
+    if rate <= -1.0 {
+        return invalid_input("rate must be greater than -1", silent);
+    }
+    Ok(Some(npv(rate, &cash_flows)))
+}
+
+/// Discounts a single future value back to the present: `FV / (1+rate)^periods`.
+#[pyfunction]
+#[pyo3(signature = (rate, periods, future_value, silent=false))]
+pub fn present_value(
+    rate: f64,
+    periods: f64,
+    future_value: f64,
+    silent: bool,
+) -> PyResult<Option<f64>> {
+    if rate <= -1.0 {
+        return invalid_input("rate must be greater than -1", silent);
+    }
+    Ok(Some(future_value / (1.0 + rate).powf(periods)))
+}
+
+/// Compounds a present value forward: `PV * (1+rate)^periods`.
+#[pyfunction]
+#[pyo3(signature = (rate, periods, present_value, silent=false))]
+pub fn future_value(
+    rate: f64,
+    periods: f64,
+    present_value: f64,
+    silent: bool,
+) -> PyResult<Option<f64>> {
+    if rate <= -1.0 {
+        return invalid_input("rate must be greater than -1", silent);
+    }
+    Ok(Some(present_value * (1.0 + rate).powf(periods)))
+}
+
+/// Compound annual growth rate: `(end/begin)^(1/years) − 1`.
+#[pyfunction]
+#[pyo3(signature = (begin_value, end_value, years, silent=false))]
+pub fn calculate_cagr(
+    begin_value: f64,
+    end_value: f64,
+    years: f64,
+    silent: bool,
+) -> PyResult<Option<f64>> {
+    if begin_value <= 0.0 {
+        return invalid_input("begin_value must be positive", silent);
+    }
+    if end_value <= 0.0 {
+        return invalid_input("end_value must be positive", silent);
+    }
+    if years <= 0.0 {
+        return invalid_input("years must be positive", silent);
+    }
+    Ok(Some((end_value / begin_value).powf(1.0 / years) - 1.0))
+}
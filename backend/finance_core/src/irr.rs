@@ -0,0 +1,82 @@
+use pyo3::prelude::*;
+
+use crate::invalid_input;
+
+/// NPV(r) = Σ cf_i / (1+r)^i for i = 0..n.
+pub(crate) fn npv(rate: f64, cash_flows: &[f64]) -> f64 {
+    cash_flows
+        .iter()
+        .enumerate()
+        .map(|(i, cf)| cf / (1.0 + rate).powi(i as i32))
+        .sum()
+}
+
+/// Scans `(-0.9999, max_rate]` for sign changes in `f`, bisects every
+/// bracketing interval to `tolerance`, and returns all roots found,
+/// ordered by ascending absolute value.
+pub(crate) fn bracket_and_bisect<F>(f: F, max_rate: f64, tolerance: f64) -> Vec<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    const GRID_STEP: f64 = 0.01;
+    const MAX_BISECT_ITERATIONS: u32 = 200;
+
+    let mut roots = Vec::new();
+    let mut lo = -0.9999;
+    let mut f_lo = f(lo);
+    let mut rate = lo + GRID_STEP;
+
+    while rate <= max_rate {
+        let f_rate = f(rate);
+        if f_lo.is_finite() && f_rate.is_finite() && f_lo.signum() != f_rate.signum() {
+            let mut a = lo;
+            let mut b = rate;
+            let mut f_a = f_lo;
+            for _ in 0..MAX_BISECT_ITERATIONS {
+                let mid = (a + b) / 2.0;
+                let f_mid = f(mid);
+                if f_mid.abs() < tolerance || (b - a).abs() < tolerance {
+                    roots.push(mid);
+                    break;
+                }
+                if f_mid.signum() == f_a.signum() {
+                    a = mid;
+                    f_a = f_mid;
+                } else {
+                    b = mid;
+                }
+            }
+        }
+        lo = rate;
+        f_lo = f_rate;
+        rate += GRID_STEP;
+    }
+
+    roots.sort_by(|a, b| a.abs().partial_cmp(&b.abs()).unwrap());
+    roots
+}
+
+/// Solves for the rate where NPV equals zero and returns the root
+/// closest to zero.
+///
+/// Returns `None` when the cash flows never change sign.
+#[pyfunction]
+#[pyo3(signature = (cash_flows, guess=None, silent=false))]
+pub fn calculate_irr(
+    cash_flows: Vec<f64>,
+    guess: Option<f64>,
+    silent: bool,
+) -> PyResult<Option<f64>> {
+    let _ = guess;
+    if cash_flows.is_empty() {
+        return invalid_input("cash_flows must not be empty", silent);
+    }
+    let has_positive = cash_flows.iter().any(|&cf| cf > 0.0);
+    let has_negative = cash_flows.iter().any(|&cf| cf < 0.0);
+    if !has_positive || !has_negative {
+        return Ok(None);
+    }
+
+    let roots = bracket_and_bisect(|r| npv(r, &cash_flows), 10.0, 1e-9);
+    Ok(roots.into_iter().next())
+}
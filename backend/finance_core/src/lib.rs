@@ -1,25 +1,74 @@
-use pyo3.prelude::*;
+// pyo3 0.20's #[pymethods] expansion trips the `non_local_definitions`
+// lint on newer rustc; harmless and not something this crate controls.
+#![allow(non_local_definitions)]
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+mod bond;
+mod core;
+mod irr;
+mod vectorized;
+mod xirr;
+
+use bond::SimpleBond;
+use core::{calculate_cagr, calculate_npv, future_value, present_value};
+use irr::calculate_irr;
+use vectorized::calculate_compound_interest_array;
+use xirr::{calculate_xirr, calculate_xnpv};
+
+/// Reports an invalid-input condition, honoring the caller's `silent`
+/// preference: `Err(ValueError)` by default so mistakes surface loudly,
+/// or `Ok(None)` when the caller opted into `silent=True` to skip bad
+/// rows during bulk computation without wrapping every call in
+/// try/except.
+pub(crate) fn invalid_input<T>(message: &str, silent: bool) -> PyResult<Option<T>> {
+    if silent {
+        Ok(None)
+    } else {
+        Err(PyValueError::new_err(message.to_string()))
+    }
+}
 
 /// Efficiently calculates compound interest.
 /// Formula: P * (1 + r/n)^(n*t)
 #[pyfunction]
-fn calculate_compound_interest(principal: f64, rate: f64, times_per_year: u32, years: u32) -> PyResult<f64> {
+#[pyo3(signature = (principal, rate, times_per_year, years, silent=false))]
+fn calculate_compound_interest(
+    principal: f64,
+    rate: f64,
+    times_per_year: u32,
+    years: u32,
+    silent: bool,
+) -> PyResult<Option<f64>> {
+    if times_per_year == 0 {
+        return invalid_input("times_per_year must be greater than zero", silent);
+    }
     let r = rate / 100.0;
     let n = times_per_year as f64;
     let t = years as f64;
     let body = 1.0 + (r / n);
     let exponent = n * t;
-    Ok(principal * body.powf(exponent))
+    Ok(Some(principal * body.powf(exponent)))
 }
 
 /// Calculates the impact of inflation on purchasing power.
 /// Formula: Amount / (1 + inflation_rate)^years
 #[pyfunction]
-fn calculate_inflation_impact(amount: f64, inflation_rate: f64, years: u32) -> PyResult<f64> {
+#[pyo3(signature = (amount, inflation_rate, years, silent=false))]
+fn calculate_inflation_impact(
+    amount: f64,
+    inflation_rate: f64,
+    years: u32,
+    silent: bool,
+) -> PyResult<Option<f64>> {
     let r = inflation_rate / 100.0;
+    if 1.0 + r <= 0.0 {
+        return invalid_input("inflation_rate must be greater than -100", silent);
+    }
     let t = years as f64;
     let denominator = (1.0 + r).powf(t);
-    Ok(amount / denominator)
+    Ok(Some(amount / denominator))
 }
 
 /// A Python module implemented in Rust.
@@ -27,5 +76,14 @@ fn calculate_inflation_impact(amount: f64, inflation_rate: f64, years: u32) -> P
 fn finance_core(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(calculate_compound_interest, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_inflation_impact, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_irr, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_xnpv, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_xirr, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_compound_interest_array, m)?)?;
+    m.add_class::<SimpleBond>()?;
+    m.add_function(wrap_pyfunction!(calculate_npv, m)?)?;
+    m.add_function(wrap_pyfunction!(present_value, m)?)?;
+    m.add_function(wrap_pyfunction!(future_value, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_cagr, m)?)?;
     Ok(())
 }
@@ -0,0 +1,51 @@
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::invalid_input;
+
+/// Element-wise `P * (1 + r/n)^(n*t)` over equal-length numpy arrays.
+///
+/// The hot loop runs inside `py.allow_threads`, releasing the GIL so
+/// callers computing portfolio-wide interest across many rows can
+/// parallelize across threads instead of serializing on a single call.
+#[pyfunction]
+#[pyo3(signature = (principals, rates, times_per_year, years, silent=false))]
+pub fn calculate_compound_interest_array<'py>(
+    py: Python<'py>,
+    principals: PyReadonlyArray1<f64>,
+    rates: PyReadonlyArray1<f64>,
+    times_per_year: PyReadonlyArray1<f64>,
+    years: PyReadonlyArray1<f64>,
+    silent: bool,
+) -> PyResult<Option<&'py PyArray1<f64>>> {
+    let principals = principals.as_array();
+    let rates = rates.as_array();
+    let times_per_year = times_per_year.as_array();
+    let years = years.as_array();
+
+    let len = principals.len();
+    if rates.len() != len || times_per_year.len() != len || years.len() != len {
+        return invalid_input(
+            "principals, rates, times_per_year, and years must have the same length",
+            silent,
+        );
+    }
+    if times_per_year.iter().any(|&n| n <= 0.0) {
+        return invalid_input("times_per_year must be greater than zero", silent);
+    }
+
+    let result = py.allow_threads(|| {
+        (0..len)
+            .map(|i| {
+                let r = rates[i] / 100.0;
+                let n = times_per_year[i];
+                let t = years[i];
+                let body = 1.0 + (r / n);
+                let exponent = n * t;
+                principals[i] * body.powf(exponent)
+            })
+            .collect::<Vec<f64>>()
+    });
+
+    Ok(Some(result.into_pyarray(py)))
+}
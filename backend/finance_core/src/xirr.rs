@@ -0,0 +1,76 @@
+use chrono::NaiveDate;
+use pyo3::prelude::*;
+use pyo3::types::{PyDate, PyDateAccess};
+
+use crate::invalid_input;
+use crate::irr::bracket_and_bisect;
+
+fn to_naive_date(date: &PyDate) -> NaiveDate {
+    NaiveDate::from_ymd_opt(date.get_year(), date.get_month() as u32, date.get_day() as u32)
+        .expect("PyDate always represents a valid calendar date")
+}
+
+/// XNPV(r) = Σ cf_i / (1+r)^((d_i − d_0)/365), using the first date as
+/// the epoch and an actual/365 day-count fraction.
+pub(crate) fn xnpv(rate: f64, dates: &[NaiveDate], amounts: &[f64]) -> f64 {
+    let epoch = dates[0];
+    dates
+        .iter()
+        .zip(amounts.iter())
+        .map(|(d, cf)| {
+            let years = (*d - epoch).num_days() as f64 / 365.0;
+            cf / (1.0 + rate).powf(years)
+        })
+        .sum()
+}
+
+/// Net present value of cash flows on arbitrary, irregularly spaced dates.
+#[pyfunction]
+#[pyo3(signature = (rate, dates, amounts, silent=false))]
+pub fn calculate_xnpv(
+    rate: f64,
+    dates: Vec<&PyDate>,
+    amounts: Vec<f64>,
+    silent: bool,
+) -> PyResult<Option<f64>> {
+    if dates.len() != amounts.len() {
+        return invalid_input("dates and amounts must have the same length", silent);
+    }
+    if dates.is_empty() {
+        return invalid_input("dates and amounts must not be empty", silent);
+    }
+    if rate <= -1.0 {
+        return invalid_input("rate must be greater than -1", silent);
+    }
+    let dates: Vec<NaiveDate> = dates.into_iter().map(to_naive_date).collect();
+    Ok(Some(xnpv(rate, &dates, &amounts)))
+}
+
+/// Solves for the rate where `calculate_xnpv` equals zero.
+///
+/// Returns `None` when the cash flows never change sign.
+#[pyfunction]
+#[pyo3(signature = (dates, amounts, guess=None, silent=false))]
+pub fn calculate_xirr(
+    dates: Vec<&PyDate>,
+    amounts: Vec<f64>,
+    guess: Option<f64>,
+    silent: bool,
+) -> PyResult<Option<f64>> {
+    let _ = guess;
+    if dates.len() != amounts.len() {
+        return invalid_input("dates and amounts must have the same length", silent);
+    }
+    if dates.is_empty() {
+        return invalid_input("dates and amounts must not be empty", silent);
+    }
+    let has_positive = amounts.iter().any(|&cf| cf > 0.0);
+    let has_negative = amounts.iter().any(|&cf| cf < 0.0);
+    if !has_positive || !has_negative {
+        return Ok(None);
+    }
+
+    let dates: Vec<NaiveDate> = dates.into_iter().map(to_naive_date).collect();
+    let roots = bracket_and_bisect(|r| xnpv(r, &dates, &amounts), 10.0, 1e-9);
+    Ok(roots.into_iter().next())
+}